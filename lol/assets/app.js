@@ -0,0 +1,146 @@
+// Front end of the home page. It creates, edits, deletes and lists notes through the JSON API
+// at /api/notes without reloading the page. Without JavaScript the page still works: the form
+// posts to /note and the notes are listed at /notes.
+//
+// Plain browser JavaScript, no build step. Text from notes only ever goes into `textContent`
+// and `value`, never into HTML.
+(function () {
+    'use strict';
+
+    var form = document.getElementById('new-note');
+    var list = document.getElementById('notes-app');
+    var status = document.getElementById('app-status');
+    var csrfToken = document.querySelector('meta[name="csrf-token"]');
+    if (!form || !list || !status || !csrfToken || !window.fetch) {
+        return;
+    }
+    csrfToken = csrfToken.getAttribute('content');
+
+    // Sends a request to the API and returns a promise of the decoded response. Errors are
+    // rejected with the message of the server.
+    function api(method, url, body) {
+        var headers = { 'Accept': 'application/json', 'X-CSRF-Token': csrfToken };
+        var options = { method: method, headers: headers, credentials: 'same-origin' };
+        if (body !== undefined) {
+            headers['Content-Type'] = 'application/json';
+            options.body = JSON.stringify(body);
+        }
+        return fetch(url, options).then(function (response) {
+            if (!response.ok) {
+                return response.text().then(function (text) {
+                    throw new Error(text || response.statusText);
+                });
+            }
+            return response.status === 204 ? null : response.json();
+        });
+    }
+
+    function showStatus(message) {
+        status.textContent = message;
+    }
+
+    function showError(error) {
+        showStatus('Something went wrong: ' + error.message);
+    }
+
+    function element(tag, text) {
+        var node = document.createElement(tag);
+        if (text !== undefined) {
+            node.textContent = text;
+        }
+        return node;
+    }
+
+    function button(label, onClick) {
+        var node = element('button', label);
+        node.type = 'button';
+        node.addEventListener('click', onClick);
+        return node;
+    }
+
+    // The list item of a note, as it is shown.
+    function renderNote(note) {
+        var item = element('li');
+        item.id = 'note_' + note.id;
+        fillNote(item, note);
+        return item;
+    }
+
+    function fillNote(item, note) {
+        item.textContent = '';
+
+        var link = element('a', note.title || 'Untitled note');
+        link.href = note.permalink || note.url;
+        item.appendChild(link);
+
+        var content = element('p', note.content);
+        content.className = 'note-content';
+        item.appendChild(content);
+
+        if (note.editable) {
+            item.appendChild(button('Edit', function () {
+                editNote(item, note);
+            }));
+        }
+        if (note.deletable) {
+            item.appendChild(button('Delete', function () {
+                if (!window.confirm('Move this note to the trash?')) {
+                    return;
+                }
+                api('DELETE', '/api/notes/' + note.id).then(function () {
+                    list.removeChild(item);
+                    showStatus('The note is in the trash.');
+                }, showError);
+            }));
+        }
+    }
+
+    // Replaces a note with a form to change it, in place.
+    function editNote(item, note) {
+        item.textContent = '';
+
+        var title = element('input');
+        title.type = 'text';
+        title.value = note.title;
+        item.appendChild(title);
+        item.appendChild(element('br'));
+
+        var content = element('textarea');
+        content.cols = 40;
+        content.rows = 5;
+        content.value = note.content;
+        item.appendChild(content);
+        item.appendChild(element('br'));
+
+        item.appendChild(button('Save', function () {
+            var changes = { title: title.value, content: content.value };
+            api('PUT', '/api/notes/' + note.id, changes).then(function (saved) {
+                fillNote(item, saved);
+                showStatus('The note has been saved.');
+            }, showError);
+        }));
+        item.appendChild(button('Cancel', function () {
+            fillNote(item, note);
+        }));
+        content.focus();
+    }
+
+    form.addEventListener('submit', function (event) {
+        event.preventDefault();
+        var note = {
+            title: form.elements.title.value,
+            content: form.elements.content.value
+        };
+        api('POST', '/api/notes', note).then(function (created) {
+            list.insertBefore(renderNote(created), list.firstChild);
+            form.reset();
+            showStatus('The note has been saved.');
+        }, showError);
+    });
+
+    api('GET', '/api/notes').then(function (notes) {
+        notes.forEach(function (note) {
+            list.appendChild(renderNote(note));
+        });
+    }, showError);
+})();